@@ -67,9 +67,14 @@ iter.debug_prefix_pretty("This person is").for_each(|_| ());
 ```
 */
 use std::borrow::Cow;
+use std::io::Write as _;
 
 /// [`DebugIterator`](./trait.DebugIterator.html) is an [`std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html) adapter that simply prints out
 /// the debug representation of the [`Iterator::Item`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item)
+// `Box::new(|item| debug_format(item))` isn't a redundant wrapper: coercing the bare fn item
+// `debug_format::<Self::Item>` into `Formatter<Self::Item>` would require `Self::Item: 'static`,
+// which nothing here guarantees. The closure keeps an anonymous, non-monomorphized type instead.
+#[allow(clippy::redundant_closure)]
 pub trait DebugIterator: Iterator {
     /// Create an adapter that prints out the [`std::fmt::Debug`](https://doc.rust-lang.org/std/fmt/trait.Debug.html) representation of the Item
     fn debug<'a>(self) -> DebugPrinter<'a, Self>
@@ -77,7 +82,7 @@ pub trait DebugIterator: Iterator {
         Self: Sized,
         Self::Item: std::fmt::Debug,
     {
-        DebugPrinter::new(self, false, None)
+        DebugPrinter::new(self, Box::new(|item| debug_format(item)), None)
     }
 
     /// Create an adapter that prints out the [`std::fmt::Debug`](https://doc.rust-lang.org/std/fmt/trait.Debug.html) alterntive representation of the Item
@@ -86,7 +91,7 @@ pub trait DebugIterator: Iterator {
         Self: Sized,
         Self::Item: std::fmt::Debug,
     {
-        DebugPrinter::new(self, true, None)
+        DebugPrinter::new(self, Box::new(|item| debug_pretty_format(item)), None)
     }
 
     /// Create an adapter that prints out the [`std::fmt::Debug`](https://doc.rust-lang.org/std/fmt/trait.Debug.html) representation of the Item, with a Prefix
@@ -96,7 +101,7 @@ pub trait DebugIterator: Iterator {
         Self::Item: std::fmt::Debug,
         S: Into<Cow<'a, str>>,
     {
-        DebugPrinter::new(self, false, Some(prefix.into()))
+        DebugPrinter::new(self, Box::new(|item| debug_format(item)), Some(prefix.into()))
     }
 
     /// Create an adapter that prints out the [`std::fmt::Debug`](https://doc.rust-lang.org/std/fmt/trait.Debug.html) alterntive representation of the Item, with a Prefix
@@ -106,51 +111,482 @@ pub trait DebugIterator: Iterator {
         Self::Item: std::fmt::Debug,
         S: Into<Cow<'a, str>>,
     {
-        DebugPrinter::new(self, true, Some(prefix.into()))
+        DebugPrinter::new(self, Box::new(|item| debug_pretty_format(item)), Some(prefix.into()))
+    }
+
+    /// Create an adapter that writes the [`std::fmt::Debug`](https://doc.rust-lang.org/std/fmt/trait.Debug.html) representation of the Item into `writer`, instead of the default sink
+    fn debug_to<'a, W>(self, writer: W) -> DebugPrinter<'a, Self>
+    where
+        Self: Sized,
+        Self::Item: std::fmt::Debug,
+        W: std::io::Write + 'static,
+    {
+        DebugPrinter::with_sink(
+            self,
+            Box::new(|item| debug_format(item)),
+            None,
+            Sink::Writer(Box::new(writer)),
+        )
+    }
+
+    /// Create an adapter that writes the [`std::fmt::Debug`](https://doc.rust-lang.org/std/fmt/trait.Debug.html) representation of the Item into `writer`, with a Prefix
+    fn debug_prefix_to<'a, S, W>(self, prefix: S, writer: W) -> DebugPrinter<'a, Self>
+    where
+        Self: Sized + 'a,
+        Self::Item: std::fmt::Debug,
+        S: Into<Cow<'a, str>>,
+        W: std::io::Write + 'static,
+    {
+        DebugPrinter::with_sink(
+            self,
+            Box::new(|item| debug_format(item)),
+            Some(prefix.into()),
+            Sink::Writer(Box::new(writer)),
+        )
+    }
+
+    /// Create an adapter that writes the [`std::fmt::Debug`](https://doc.rust-lang.org/std/fmt/trait.Debug.html) representation of the Item through the `log` crate facade, at `level` instead of the default `Debug` level
+    #[cfg(feature = "logging")]
+    fn debug_at<'a>(self, level: ::log::Level) -> DebugPrinter<'a, Self>
+    where
+        Self: Sized,
+        Self::Item: std::fmt::Debug,
+    {
+        DebugPrinter::with_sink(
+            self,
+            Box::new(|item| debug_format(item)),
+            None,
+            Sink::Log {
+                level,
+                target: None,
+            },
+        )
+    }
+
+    /// Create an adapter that only prints every `n`th item (indices `0`, `n`, `2n`, ...), while still yielding every item downstream
+    fn debug_every<'a>(self, n: usize) -> DebugPrinter<'a, Self>
+    where
+        Self: Sized,
+        Self::Item: std::fmt::Debug,
+    {
+        DebugPrinter::with_limit(self, Box::new(|item| debug_format(item)), None, Limit::Every(n))
+    }
+
+    /// Create an adapter that only prints the first `n` items, then goes silent, while still yielding every item downstream
+    fn debug_take<'a>(self, n: usize) -> DebugPrinter<'a, Self>
+    where
+        Self: Sized,
+        Self::Item: std::fmt::Debug,
+    {
+        DebugPrinter::with_limit(self, Box::new(|item| debug_format(item)), None, Limit::Take(n))
+    }
+
+    /// Create an adapter that prepends the running item index to each printed line, e.g. `[0] Person { ... }`
+    ///
+    /// Composes with [`debug_prefix`](#method.debug_prefix): `iter.debug_prefix("This person is").with_index()` yields `This person is [2]: Person { ... }`.
+    fn debug_enumerate<'a>(self) -> DebugPrinter<'a, Self>
+    where
+        Self: Sized,
+        Self::Item: std::fmt::Debug,
+    {
+        self.debug().with_index()
+    }
+
+    /// Create an adapter that prepends the running item index to each printed line, using the [`std::fmt::Debug`](https://doc.rust-lang.org/std/fmt/trait.Debug.html) alterntive representation of the Item
+    fn debug_enumerate_pretty<'a>(self) -> DebugPrinter<'a, Self>
+    where
+        Self: Sized,
+        Self::Item: std::fmt::Debug,
+    {
+        self.debug_pretty().with_index()
+    }
+
+    /// Create an adapter that formats each Item with a custom closure instead of [`std::fmt::Debug`](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+    ///
+    /// This drops the `Self::Item: Debug` bound for this path, so it also works for items that only implement
+    /// [`std::fmt::Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html), or for printing a selected field or a truncated summary.
+    fn debug_with<'a, F>(self, f: F) -> DebugPrinter<'a, Self>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> String + 'static,
+    {
+        DebugPrinter::new(self, Box::new(f), None)
+    }
+}
+
+/// The closure a [`DebugPrinter`](./struct.DebugPrinter.html) calls to turn an item into the line it prints
+type Formatter<T> = Box<dyn FnMut(&T) -> String>;
+
+/// The formatter used by `debug()` and friends; `debug_with` builds its printer with the caller's closure instead
+fn debug_format<T: std::fmt::Debug>(item: &T) -> String {
+    format!("{:?}", item)
+}
+
+/// The formatter used by `debug_pretty()` and friends
+fn debug_pretty_format<T: std::fmt::Debug>(item: &T) -> String {
+    format!("{:#?}", item)
+}
+
+/// Which items a [`DebugPrinter`](./struct.DebugPrinter.html) actually prints
+enum Limit {
+    /// Print every item
+    None,
+    /// Print items at indices `0, n, 2n, ...`
+    Every(usize),
+    /// Print only the first `n` items
+    Take(usize),
+}
+
+impl Limit {
+    /// Whether the item at `index` should be printed
+    fn allows(&self, index: usize) -> bool {
+        match *self {
+            Limit::None => true,
+            Limit::Every(n) => n != 0 && index.is_multiple_of(n),
+            Limit::Take(n) => index < n,
+        }
+    }
+}
+
+/// Where a [`DebugPrinter`](./struct.DebugPrinter.html) writes its formatted lines
+enum Sink {
+    /// Write to `stderr`, via `writeln!` on `std::io::stderr()`
+    #[cfg(not(feature = "logging"))]
+    Stderr,
+    /// Write through the `log` crate facade, via `log::log!`, at a chosen level and optional target
+    #[cfg(feature = "logging")]
+    Log {
+        level: ::log::Level,
+        target: Option<Cow<'static, str>>,
+    },
+    /// Write into an arbitrary [`std::io::Write`](https://doc.rust-lang.org/std/io/trait.Write.html)
+    Writer(Box<dyn std::io::Write>),
+}
+
+impl Sink {
+    /// The sink used when none is explicitly chosen: `Log` if the `logging` feature is enabled, `Stderr` otherwise
+    fn default_sink() -> Self {
+        #[cfg(feature = "logging")]
+        {
+            Sink::Log {
+                level: ::log::Level::Debug,
+                target: None,
+            }
+        }
+        #[cfg(not(feature = "logging"))]
+        {
+            Sink::Stderr
+        }
     }
 }
 
 /// [`DebugPrinter`](./struct.DebugPrinter.html) is the iterator for debug printing
-pub struct DebugPrinter<'a, T>(T, bool, Option<Cow<'a, str>>);
+pub struct DebugPrinter<'a, T>
+where
+    T: Iterator,
+{
+    iter: T,
+    format: Formatter<T::Item>,
+    prefix: Option<Cow<'a, str>>,
+    sink: Sink,
+    limit: Limit,
+    enumerate: bool,
+    index: usize,
+    error: Option<std::io::Error>,
+}
 
 impl<'a, T> DebugPrinter<'a, T>
 where
     T: Iterator,
-    T::Item: std::fmt::Debug,
 {
-    fn new(x: T, pretty: bool, msg: Option<Cow<'a, str>>) -> Self {
-        Self(x, pretty, msg)
+    fn new(
+        iter: T,
+        format: Formatter<T::Item>,
+        prefix: Option<Cow<'a, str>>,
+    ) -> Self {
+        Self::with_sink(iter, format, prefix, Sink::default_sink())
+    }
+
+    fn with_sink(
+        iter: T,
+        format: Formatter<T::Item>,
+        prefix: Option<Cow<'a, str>>,
+        sink: Sink,
+    ) -> Self {
+        Self::build(iter, format, prefix, sink, Limit::None)
+    }
+
+    fn with_limit(
+        iter: T,
+        format: Formatter<T::Item>,
+        prefix: Option<Cow<'a, str>>,
+        limit: Limit,
+    ) -> Self {
+        Self::build(iter, format, prefix, Sink::default_sink(), limit)
+    }
+
+    fn build(
+        iter: T,
+        format: Formatter<T::Item>,
+        prefix: Option<Cow<'a, str>>,
+        sink: Sink,
+        limit: Limit,
+    ) -> Self {
+        Self {
+            iter,
+            format,
+            prefix,
+            sink,
+            limit,
+            enumerate: false,
+            index: 0,
+            error: None,
+        }
+    }
+
+    /// Takes the first [`std::io::Error`](https://doc.rust-lang.org/std/io/struct.Error.html) produced while writing to the sink, if any.
+    ///
+    /// Once an error is taken, a later write failure can be captured again -- only the earliest
+    /// error between two calls to this method is kept.
+    pub fn take_error(&mut self) -> Option<std::io::Error> {
+        self.error.take()
+    }
+
+    /// Sets the `log` target used by the `Log` sink, e.g. from [`debug_at`](trait.DebugIterator.html#method.debug_at).
+    ///
+    /// Has no effect if the sink isn't `Log`.
+    ///
+    /// Unlike [`debug_prefix`](trait.DebugIterator.html#method.debug_prefix), this takes `Cow<'static, str>`
+    /// rather than `Cow<'a, str>`: a log target is conventionally a `&'static str` (a module path or a
+    /// fixed label), and `Sink` itself isn't parameterized by `'a`, so pinning it to `'static` here avoids
+    /// threading the printer's lifetime into a type that otherwise doesn't need it.
+    #[cfg(feature = "logging")]
+    pub fn target<S>(mut self, target: S) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        if let Sink::Log { target: t, .. } = &mut self.sink {
+            *t = Some(target.into());
+        }
+        self
+    }
+
+    /// Prepends the running item index to each printed line, e.g. `[0] Person { ... }`
+    ///
+    /// Named `with_index` rather than `enumerate` so it doesn't shadow [`Iterator::enumerate`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.enumerate).
+    pub fn with_index(mut self) -> Self {
+        self.enumerate = true;
+        self
     }
 }
 
 impl<'a, T> Iterator for DebugPrinter<'a, T>
 where
     T: Iterator,
-    T::Item: std::fmt::Debug,
 {
     type Item = T::Item;
     fn next(&mut self) -> Option<Self::Item> {
-        let next = self.0.next()?;
+        let next = self.iter.next()?;
 
-        #[inline]
-        macro_rules! _log_this {
-            ($e:expr, $($xs:expr),* $(,)?) => {{
-                #[cfg(feature = "logging")]
-                ::log::debug!("{}", format_args!($e, $($xs),*));
+        let index = self.index;
+        self.index += 1;
+
+        if self.limit.allows(index) {
+            let lead = match (&self.prefix, self.enumerate) {
+                (Some(prefix), true) => format!("{} [{}]: ", prefix, index),
+                (Some(prefix), false) => format!("{}: ", prefix),
+                (None, true) => format!("[{}] ", index),
+                (None, false) => String::new(),
+            };
+            let line = format!("{}{}", lead, (self.format)(&next));
 
+            match &mut self.sink {
                 #[cfg(not(feature = "logging"))]
-                eprintln!("{}", format_args!($e, $($xs),*));
-            }};
+                Sink::Stderr => {
+                    if let Err(err) = writeln!(std::io::stderr(), "{}", line) {
+                        self.error.get_or_insert(err);
+                    }
+                }
+                #[cfg(feature = "logging")]
+                Sink::Log { level, target } => match target {
+                    Some(t) => ::log::log!(target: t, *level, "{}", line),
+                    None => ::log::log!(*level, "{}", line),
+                },
+                Sink::Writer(writer) => {
+                    if let Err(err) = writeln!(writer, "{}", line) {
+                        self.error.get_or_insert(err);
+                    }
+                }
+            }
         }
 
-        match (self.1, &self.2) {
-            (true, Some(prefix)) => _log_this!("{}: {:#?}", prefix, next),
-            (false, Some(prefix)) => _log_this!("{}: {:?}", prefix, next),
-            (true, None) => _log_this!("{:#?}", next),
-            (false, None) => _log_this!("{:?}", next),
-        }
         Some(next)
     }
 }
 
 impl<T: ?Sized> DebugIterator for T where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limit_none_allows_everything() {
+        let limit = Limit::None;
+        assert!(limit.allows(0));
+        assert!(limit.allows(1));
+        assert!(limit.allows(1000));
+    }
+
+    #[test]
+    fn limit_every_zero_never_allows() {
+        let limit = Limit::Every(0);
+        assert!(!limit.allows(0));
+        assert!(!limit.allows(1));
+        assert!(!limit.allows(100));
+    }
+
+    #[test]
+    fn limit_every_allows_multiples() {
+        let limit = Limit::Every(3);
+        assert!(limit.allows(0));
+        assert!(!limit.allows(1));
+        assert!(!limit.allows(2));
+        assert!(limit.allows(3));
+        assert!(limit.allows(6));
+    }
+
+    #[test]
+    fn limit_take_boundary() {
+        let limit = Limit::Take(2);
+        assert!(limit.allows(0));
+        assert!(limit.allows(1));
+        assert!(!limit.allows(2));
+        assert!(!limit.allows(3));
+    }
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(std::rc::Rc<std::cell::RefCell<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn debug_to_writes_formatted_lines_to_the_writer() {
+        let buf = SharedBuf::default();
+        let mut iter = vec![1, 2].into_iter().debug_to(buf.clone());
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+
+        let written = String::from_utf8(buf.0.borrow().clone()).unwrap();
+        assert_eq!(written, "1\n2\n");
+    }
+
+    #[test]
+    fn custom_formatter_is_used_instead_of_debug() {
+        let buf = SharedBuf::default();
+        let mut iter = DebugPrinter::with_sink(
+            vec!["a", "bb"].into_iter(),
+            Box::new(|item: &&str| item.to_uppercase()),
+            None,
+            Sink::Writer(Box::new(buf.clone())),
+        );
+        assert_eq!(iter.next(), Some("a"));
+        assert_eq!(iter.next(), Some("bb"));
+
+        let written = String::from_utf8(buf.0.borrow().clone()).unwrap();
+        assert_eq!(written, "A\nBB\n");
+    }
+
+    #[derive(Clone, Default)]
+    struct FailingWriter(std::rc::Rc<std::cell::Cell<usize>>);
+
+    impl FailingWriter {
+        fn fail_next(&self, times: usize) {
+            self.0.set(times);
+        }
+    }
+
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let remaining = self.0.get();
+            if remaining > 0 {
+                self.0.set(remaining - 1);
+                return Err(std::io::Error::other("write failed"));
+            }
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn take_error_captures_the_earliest_write_failure_and_resets() {
+        let writer = FailingWriter::default();
+        writer.fail_next(1);
+        let mut iter = vec![1, 2, 3].into_iter().debug_to(writer.clone());
+
+        assert_eq!(iter.next(), Some(1));
+        assert!(iter.take_error().is_some());
+        assert!(iter.take_error().is_none());
+
+        assert_eq!(iter.next(), Some(2));
+        assert!(iter.take_error().is_none());
+
+        writer.fail_next(1);
+        assert_eq!(iter.next(), Some(3));
+        assert!(iter.take_error().is_some());
+        assert!(iter.take_error().is_none());
+    }
+
+    #[test]
+    fn with_index_prepends_the_running_index() {
+        let buf = SharedBuf::default();
+        let mut iter = vec!["a", "b"].into_iter().debug_to(buf.clone()).with_index();
+        assert_eq!(iter.next(), Some("a"));
+        assert_eq!(iter.next(), Some("b"));
+
+        let written = String::from_utf8(buf.0.borrow().clone()).unwrap();
+        assert_eq!(written, "[0] \"a\"\n[1] \"b\"\n");
+    }
+
+    #[test]
+    fn debug_prefix_with_index_combines_the_prefix_and_the_index() {
+        let buf = SharedBuf::default();
+        let mut iter = vec!["a", "b"]
+            .into_iter()
+            .debug_prefix_to("This person is", buf.clone())
+            .with_index();
+        assert_eq!(iter.next(), Some("a"));
+        assert_eq!(iter.next(), Some("b"));
+
+        let written = String::from_utf8(buf.0.borrow().clone()).unwrap();
+        assert_eq!(
+            written,
+            "This person is [0]: \"a\"\nThis person is [1]: \"b\"\n"
+        );
+    }
+
+    #[cfg(feature = "logging")]
+    #[test]
+    fn debug_at_and_target_configure_the_log_sink() {
+        let printer = vec![1].into_iter().debug_at(::log::Level::Trace).target("foo");
+
+        match &printer.sink {
+            Sink::Log { level, target } => {
+                assert_eq!(*level, ::log::Level::Trace);
+                assert_eq!(target.as_deref(), Some("foo"));
+            }
+            _ => panic!("expected Sink::Log"),
+        }
+    }
+}